@@ -0,0 +1,96 @@
+// 引入revolt_quark库中的各种模块和类型
+use std::collections::HashSet;
+
+use revolt_quark::{
+    events::{Event, Publish},
+    models::{server::PartialMember, Member, User},
+    perms, Db, Error, Permission, Ref, Result,
+};
+
+use rocket::serde::json::Json; // 引入Rocket框架的JSON支持
+use serde::{Deserialize, Serialize}; // 引入Serde的序列化与反序列化支持
+use validator::Validate; // 引入validator库支持数据验证
+
+/// # 成员数据
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataMemberEdit {
+    /// 要赋予该成员的角色
+    roles: Option<Vec<String>>,
+}
+
+/// # 编辑成员
+///
+/// 编辑服务器上某个成员，包括其角色分配。
+///
+/// 角色分配同样受层级约束：操作者不得赋予排名在权限上等同或凌驾于自身的角色，
+/// 其方式与权限覆盖经由 `OverrideField` 流转时一致。服务器拥有者跳过该校验。
+#[openapi(tag = "Server Members")]
+#[patch("/<target>/members/<member_id>", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    member_id: String,
+    data: Json<DataMemberEdit>,
+) -> Result<Json<Member>> {
+    let data = data.into_inner();
+    // 验证数据
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let server = target.as_server(db).await?;
+    let mut permissions = perms(&user).server(&server);
+
+    // 计算操作者的最高排名，服务器拥有者凌驾于一切角色之上
+    let rank = if user.id == server.owner {
+        i64::MIN
+    } else {
+        let member = db.fetch_member(&server.id, &user.id).await?;
+        server.member_rank(&user.id, &member.roles)
+    };
+
+    let mut member = db.fetch_member(&server.id, &member_id).await?;
+
+    // 操作者必须在权限上严格高于目标成员本身，才能编辑其角色
+    let target_rank = server.member_rank(&member_id, &member.roles);
+    if !server.has_authority_over(rank, target_rank) {
+        return Err(Error::NotElevated);
+    }
+
+    let partial = if let Some(roles) = data.roles {
+        // 分配角色需要管理角色的权限
+        permissions
+            .throw_permission(db, Permission::ManageRole)
+            .await?;
+
+        // 新列表会替换成员现有的角色集，因此被添加与被移除的角色都要受层级约束：
+        // 操作者不得赋予或剥离排名在权限上等同或凌驾于自身的角色
+        let current: HashSet<&String> = member.roles.iter().collect();
+        let next: HashSet<&String> = roles.iter().collect();
+        for role_id in current.symmetric_difference(&next) {
+            let role = server.roles.get(*role_id).ok_or(Error::NotFound)?;
+            if !server.has_authority_over(rank, role.rank) {
+                return Err(Error::NotElevated);
+            }
+        }
+
+        PartialMember {
+            roles: Some(roles),
+            ..Default::default()
+        }
+    } else {
+        return Ok(Json(member));
+    };
+
+    member.update(db, partial.clone(), vec![]).await?;
+
+    // 变更成功后，发布一次携带差异的网关事件
+    db.publish(Event::MemberUpdate {
+        id: member_id,
+        data: partial,
+        clear: vec![],
+    })
+    .await?;
+
+    Ok(Json(member))
+}
@@ -0,0 +1,96 @@
+// 引入revolt_quark库中的各种模块和类型
+use revolt_quark::{
+    events::{Event, Publish},
+    models::{
+        server::{PartialRole, Role},
+        User,
+    },
+    perms, Db, Error, Permission, Ref, Result,
+};
+
+use rocket::serde::json::Json; // 引入Rocket框架的JSON支持
+use serde::{Deserialize, Serialize}; // 引入Serde的序列化与反序列化支持
+use ulid::Ulid; // 用于生成角色的唯一Id
+use validator::Validate; // 引入validator库支持数据验证
+
+/// # 角色创建数据
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataCreateRole {
+    /// 角色名称
+    #[validate(length(min = 1, max = 32))]
+    name: String,
+    /// 角色的排名
+    rank: Option<i64>,
+}
+
+/// # 新建角色
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct NewRoleResponse {
+    /// 新角色的Id
+    id: String,
+    /// 新角色本身
+    role: Role,
+}
+
+/// # 创建角色
+///
+/// 在服务器上创建一个新角色。
+///
+/// 除了 [`Permission::ManageRole`] 之外，还会校验层级：操作者不得创建排名在
+/// 权限上等同或凌驾于自身的角色。服务器拥有者跳过该校验。
+#[openapi(tag = "Server Permissions")]
+#[post("/<target>/roles", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<DataCreateRole>,
+) -> Result<Json<NewRoleResponse>> {
+    let data = data.into_inner();
+    // 验证数据
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let server = target.as_server(db).await?;
+    let mut permissions = perms(&user).server(&server);
+    permissions
+        .throw_permission(db, Permission::ManageRole)
+        .await?;
+
+    // 计算操作者的最高排名，服务器拥有者凌驾于一切角色之上
+    let rank = if user.id == server.owner {
+        i64::MIN
+    } else {
+        let member = db.fetch_member(&server.id, &user.id).await?;
+        server.member_rank(&user.id, &member.roles)
+    };
+
+    // 新角色的排名不得越过操作者自身的层级
+    let new_rank = data.rank.unwrap_or_default();
+    if !server.has_authority_over(rank, new_rank) {
+        return Err(Error::NotElevated);
+    }
+
+    let id = Ulid::new().to_string();
+    let role = Role {
+        name: data.name,
+        rank: new_rank,
+        ..Default::default()
+    };
+
+    server.create_role(db, &id, role.clone()).await?;
+
+    // 创建成功后，发布一次携带差异的网关事件
+    db.publish(Event::RoleUpdate {
+        id: id.clone(),
+        data: PartialRole {
+            name: Some(role.name.clone()),
+            rank: Some(role.rank),
+            ..Default::default()
+        },
+        clear: vec![],
+    })
+    .await?;
+
+    Ok(Json(NewRoleResponse { id, role }))
+}
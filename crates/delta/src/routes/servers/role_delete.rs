@@ -0,0 +1,43 @@
+// 引入revolt_quark库中的各种模块和类型
+use revolt_quark::{
+    events::{Event, Publish},
+    models::User,
+    perms, Db, Error, Permission, Ref, Result,
+};
+
+/// # 删除角色
+///
+/// 删除服务器上的某个角色。
+///
+/// 除了 [`Permission::ManageRole`] 之外，还会校验层级：操作者不得删除排名在
+/// 权限上等同或凌驾于自身的角色。服务器拥有者跳过该校验。
+#[openapi(tag = "Server Permissions")]
+#[delete("/<target>/roles/<role_id>")]
+pub async fn req(db: &Db, user: User, target: Ref, role_id: String) -> Result<()> {
+    let server = target.as_server(db).await?;
+    let mut permissions = perms(&user).server(&server);
+    permissions
+        .throw_permission(db, Permission::ManageRole)
+        .await?;
+
+    let role = server.roles.get(&role_id).ok_or(Error::NotFound)?.clone();
+
+    // 计算操作者的最高排名，服务器拥有者凌驾于一切角色之上
+    let rank = if user.id == server.owner {
+        i64::MIN
+    } else {
+        let member = db.fetch_member(&server.id, &user.id).await?;
+        server.member_rank(&user.id, &member.roles)
+    };
+
+    if !server.has_authority_over(rank, role.rank) {
+        return Err(Error::NotElevated);
+    }
+
+    server.delete_role(db, &role_id).await?;
+
+    // 删除成功后，通知订阅者该角色已不复存在
+    db.publish(Event::RoleDelete { id: role_id }).await?;
+
+    Ok(())
+}
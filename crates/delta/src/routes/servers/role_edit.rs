@@ -0,0 +1,116 @@
+// 引入revolt_quark库中的各种模块和类型
+use revolt_quark::{
+    events::{Event, Publish},
+    models::{
+        server::{FieldsRole, PartialRole},
+        Member, Server, User,
+    },
+    perms, Db, Error, Permission, Ref, Result,
+};
+
+use rocket::serde::json::Json; // 引入Rocket框架的JSON支持
+use serde::{Deserialize, Serialize}; // 引入Serde的序列化与反序列化支持
+use validator::Validate; // 引入validator库支持数据验证
+
+/// # 角色数据
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataEditRole {
+    /// 角色名称
+    #[validate(length(min = 1, max = 32))]
+    name: Option<String>,
+    /// 角色颜色（任何有效的CSS颜色）
+    #[validate(length(min = 1, max = 128))]
+    colour: Option<String>,
+    /// 是否在成员侧边栏单独显示此角色
+    hoist: Option<bool>,
+    /// 角色的排名
+    rank: Option<i64>,
+
+    /// 从角色对象中移除的字段
+    #[validate(length(min = 1))]
+    remove: Option<Vec<FieldsRole>>,
+}
+
+/// 计算操作者在该服务器上的最高排名，服务器拥有者凌驾于一切角色之上。
+async fn actor_rank(db: &Db, server: &Server, user: &User) -> Result<i64> {
+    if user.id == server.owner {
+        return Ok(i64::MIN);
+    }
+
+    let member = db.fetch_member(&server.id, &user.id).await?;
+    Ok(server.member_rank(&user.id, &member.roles))
+}
+
+/// # 编辑角色
+///
+/// 编辑服务器上的某个角色。
+///
+/// 除了 [`Permission::ManageRole`] 之外，还会校验层级：操作者不得变更排名
+/// 在权限上等同或凌驾于自身的角色。服务器拥有者跳过该校验。
+#[openapi(tag = "Server Permissions")]
+#[patch("/<target>/roles/<role_id>", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    role_id: String,
+    data: Json<DataEditRole>,
+) -> Result<Json<Server>> {
+    let data = data.into_inner();
+    // 验证数据
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let server = target.as_server(db).await?;
+    let mut permissions = perms(&user).server(&server);
+    permissions
+        .throw_permission(db, Permission::ManageRole)
+        .await?;
+
+    // 定位目标角色
+    let role = server.roles.get(&role_id).ok_or(Error::NotFound)?.clone();
+
+    // 校验层级：操作者必须在权限上严格高于目标角色
+    let rank = actor_rank(db, &server, &user).await?;
+    if !server.has_authority_over(rank, role.rank) {
+        return Err(Error::NotElevated);
+    }
+
+    // 重排排名时，新的排名同样不得越过操作者自身的层级
+    if let Some(new_rank) = data.rank {
+        if !server.has_authority_over(rank, new_rank) {
+            return Err(Error::NotElevated);
+        }
+    }
+
+    let DataEditRole {
+        name,
+        colour,
+        hoist,
+        rank: new_rank,
+        remove,
+    } = data;
+
+    let partial = PartialRole {
+        name,
+        colour,
+        hoist,
+        rank: new_rank,
+        ..Default::default()
+    };
+
+    let remove = remove.unwrap_or_default();
+    server
+        .update_role(db, &role_id, partial.clone(), remove.clone())
+        .await?;
+
+    // 发布一次携带差异的网关事件
+    db.publish(Event::RoleUpdate {
+        id: role_id,
+        data: partial,
+        clear: remove,
+    })
+    .await?;
+
+    Ok(Json(server))
+}
@@ -5,9 +5,10 @@ use std::collections::HashSet;
 use revolt_quark::{
     models::{
         // 服务器相关的模型，例如分类、服务器的部分信息、系统消息通道等
-        server::{Category, FieldsServer, PartialServer, SystemMessageChannels},
+        server::{Category, FieldsServer, PartialServer, ServerFlags, SystemMessageChannels},
         File, Server, User, // 文件、服务器、用户模型
     },
+    events::{Event, Publish}, // 网关事件与发布总线
     perms, Db, Error, Permission, Ref, Result, // 权限、数据库、错误、权限、引用、结果类型
 };
 
@@ -36,9 +37,9 @@ pub struct DataEditServer {
     /// 系统消息配置
     system_messages: Option<SystemMessageChannels>,
 
-    /// 服务器标志的位字段
+    /// 服务器标志的位集
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flags: Option<i32>,
+    pub flags: Option<ServerFlags>,
 
     // 是否这个服务器是限制年龄的
     // nsfw: Option<bool>,
@@ -197,9 +198,16 @@ pub async fn req(
     }
 
     // 应用更改到服务器
-    server
-        .update(db, partial, remove.unwrap_or_default())
-        .await?;
+    let remove = remove.unwrap_or_default();
+    server.update(db, partial.clone(), remove.clone()).await?;
+
+    // 本次变更成功后，发布一次携带差异的网关事件
+    db.publish(Event::ServerUpdate {
+        id: server.id.clone(),
+        data: partial,
+        clear: remove,
+    })
+    .await?;
 
     Ok(Json(server))
 }
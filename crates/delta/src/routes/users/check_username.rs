@@ -0,0 +1,44 @@
+// 引入revolt_quark库中的各种模块和类型
+use revolt_quark::{models::User, Db, Error, Result}; // 数据库、错误、用户模型与结果类型
+
+use rocket::serde::json::Json; // 引入Rocket框架的JSON支持
+use serde::{Deserialize, Serialize}; // 引入Serde的序列化与反序列化支持
+use validator::Validate; // 引入validator库支持数据验证
+
+/// # 检查用户名数据
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataCheckUsername {
+    /// 期望的用户名
+    #[validate(length(min = 2, max = 32))]
+    username: String,
+}
+
+/// # 用户名可用性
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ResponseUsernameAvailability {
+    /// 该用户名当前是否空闲
+    available: bool,
+}
+
+/// # 检查用户名
+///
+/// 检查某个期望的用户名当前是否可以被占用。
+#[openapi(tag = "User Migration")]
+#[post("/username/check", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    _user: User,
+    data: Json<DataCheckUsername>,
+) -> Result<Json<ResponseUsernameAvailability>> {
+    let data = data.into_inner();
+    // 验证数据
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    // 以规范化后的句柄查询，避免大小写或非法字符造成的误判
+    let username = User::normalize_username(&data.username);
+    // 规范化可能把输入缩短到下限以下甚至为空，这类句柄永远不可用
+    let available = username.len() >= 2 && !db.is_username_taken(&username).await?;
+
+    Ok(Json(ResponseUsernameAvailability { available }))
+}
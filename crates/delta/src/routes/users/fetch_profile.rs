@@ -0,0 +1,80 @@
+// 引入revolt_quark库中的各种模块和类型
+use revolt_quark::{
+    models::{user::RelationshipStatus, User, UserProfile},
+    Db, Ref, Result,
+};
+
+use rocket::serde::json::Json; // 引入Rocket框架的JSON支持
+use serde::{Deserialize, Serialize}; // 引入Serde的序列化与反序列化支持
+
+/// # 用户资料
+///
+/// 在用户资料之外，可选地附带与请求方之间的关系上下文。
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ResponseUserProfile {
+    /// 用户资料本身
+    #[serde(flatten)]
+    profile: UserProfile,
+    /// 双方共同的好友 Id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mutual_friends: Option<Vec<String>>,
+    /// 双方共同所在的服务器 Id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mutual_servers: Option<Vec<String>>,
+}
+
+/// # 获取用户资料
+///
+/// 获取某个用户的资料，并可通过查询标志额外计算共同好友与共同服务器。
+#[openapi(tag = "User Information")]
+#[get("/<target>/profile?<mutual_friends>&<mutual_servers>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    mutual_friends: bool,
+    mutual_servers: bool,
+) -> Result<Json<ResponseUserProfile>> {
+    let target = target.as_user(db).await?;
+
+    let profile = target.profile.clone().unwrap_or_default();
+
+    // 任意一方处于屏蔽状态时，不暴露任何共同关系
+    let blocked = matches!(
+        user.relationship_with(&target.id),
+        RelationshipStatus::Blocked | RelationshipStatus::BlockedOther
+    ) || matches!(
+        target.relationship_with(&user.id),
+        RelationshipStatus::Blocked | RelationshipStatus::BlockedOther
+    );
+
+    // 机器人没有社交关系，其共同集合始终为空
+    let is_bot = user.bot.is_some() || target.bot.is_some();
+
+    // 屏蔽时整块省略；机器人没有社交关系，返回空集合
+    let mutual_friends = if mutual_friends && !blocked {
+        Some(if is_bot {
+            vec![]
+        } else {
+            user.mutual_friend_ids(&target)
+        })
+    } else {
+        None
+    };
+
+    let mutual_servers = if mutual_servers && !blocked {
+        Some(if is_bot {
+            vec![]
+        } else {
+            db.fetch_mutual_server_ids(&user.id, &target.id).await?
+        })
+    } else {
+        None
+    };
+
+    Ok(Json(ResponseUserProfile {
+        profile,
+        mutual_friends,
+        mutual_servers,
+    }))
+}
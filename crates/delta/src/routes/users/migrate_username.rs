@@ -0,0 +1,91 @@
+// 引入revolt_quark库中的各种模块和类型
+use revolt_quark::{
+    events::{Event, Publish},
+    models::{user::PartialUser, User},
+    Db, Error, Result,
+};
+
+use rocket::serde::json::Json; // 引入Rocket框架的JSON支持
+use serde::{Deserialize, Serialize}; // 引入Serde的序列化与反序列化支持
+use validator::Validate; // 引入validator库支持数据验证
+
+/// # 迁移资格
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ResponseMigrationEligibility {
+    /// 当前账户是否可以迁移到全局唯一的用户名
+    eligible: bool,
+}
+
+/// # 用户名迁移数据
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataMigrateUsername {
+    /// 要占用的全局唯一用户名
+    #[validate(length(min = 2, max = 32))]
+    username: String,
+}
+
+/// # 检查迁移资格
+///
+/// 返回当前会话账户是否有资格迁移到全局唯一的用户名。
+#[openapi(tag = "User Migration")]
+#[get("/username/eligibility")]
+pub async fn eligibility(_db: &Db, user: User) -> Result<Json<ResponseMigrationEligibility>> {
+    Ok(Json(ResponseMigrationEligibility {
+        eligible: user.can_migrate_username(),
+    }))
+}
+
+/// # 迁移用户名
+///
+/// 将当前账户迁移到一个全局唯一的用户名并退役判别符；若尚无显示名，则把旧的友好名保留为显示名。
+#[openapi(tag = "User Migration")]
+#[patch("/username/migrate", data = "<data>")]
+pub async fn migrate(
+    db: &Db,
+    mut user: User,
+    data: Json<DataMigrateUsername>,
+) -> Result<Json<User>> {
+    let data = data.into_inner();
+    // 验证数据
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    // 仅允许符合资格的账户迁移
+    if !user.can_migrate_username() {
+        return Err(Error::InvalidOperation);
+    }
+
+    // 规范化并确保所请求的用户名仍然空闲
+    let username = User::normalize_username(&data.username);
+    // 校验规范化后的句柄：去除非法字符后可能缩短到下限以下甚至为空
+    if username.len() < 2 {
+        return Err(Error::InvalidOperation);
+    }
+    if db.is_username_taken(&username).await? {
+        return Err(Error::InvalidOperation);
+    }
+
+    let mut partial = PartialUser {
+        username: Some(username),
+        // 判别符退役为保留值
+        discriminator: Some("0".to_string()),
+        ..Default::default()
+    };
+
+    // 若尚无显示名，则把旧的友好名迁移过去
+    if user.display_name.is_none() {
+        partial.display_name = Some(format!("{}#{}", user.username, user.discriminator));
+    }
+
+    user.update(db, partial.clone(), vec![]).await?;
+
+    // 迁移成功后，发布一次携带差异的网关事件
+    db.publish(Event::UserUpdate {
+        id: user.id.clone(),
+        data: partial,
+        clear: vec![],
+    })
+    .await?;
+
+    Ok(Json(user))
+}
@@ -0,0 +1,53 @@
+// 引入revolt_quark库中的各种模块和类型
+use revolt_quark::{models::User, Db, Error, Result}; // 数据库、错误、用户模型与结果类型
+
+use rocket::serde::json::Json; // 引入Rocket框架的JSON支持
+use serde::{Deserialize, Serialize}; // 引入Serde的序列化与反序列化支持
+use validator::Validate; // 引入validator库支持数据验证
+
+/// # 用户名建议数据
+#[derive(Validate, Serialize, Deserialize, JsonSchema)]
+pub struct DataSuggestUsername {
+    /// 作为建议基础的期望句柄
+    #[validate(length(min = 2, max = 32))]
+    username: String,
+}
+
+/// # 用户名建议
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ResponseUsernameSuggestions {
+    /// 一批当前空闲的用户名建议
+    suggestions: Vec<String>,
+}
+
+/// 返回的建议数量上限
+const SUGGESTION_COUNT: usize = 5;
+
+/// # 建议用户名
+///
+/// 根据一个期望的基础句柄返回一批当前可占用的用户名建议。
+#[openapi(tag = "User Migration")]
+#[post("/username/suggest", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    _user: User,
+    data: Json<DataSuggestUsername>,
+) -> Result<Json<ResponseUsernameSuggestions>> {
+    let data = data.into_inner();
+    // 验证数据
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    let mut suggestions = Vec::with_capacity(SUGGESTION_COUNT);
+    for candidate in User::suggest_usernames(&data.username) {
+        if suggestions.len() >= SUGGESTION_COUNT {
+            break;
+        }
+
+        if !db.is_username_taken(&candidate).await? {
+            suggestions.push(candidate);
+        }
+    }
+
+    Ok(Json(ResponseUsernameSuggestions { suggestions }))
+}
@@ -0,0 +1,75 @@
+// 序列化和反序列化支持
+use serde::{Deserialize, Serialize};
+
+// 进程内事件总线所需的类型
+use once_cell::sync::Lazy;
+use tokio::sync::broadcast;
+
+// 引入受影响模型的部分结构与可选字段枚举
+use crate::models::{
+    server::{FieldsMember, FieldsRole, FieldsServer, PartialMember, PartialRole, PartialServer},
+    user::{FieldsUser, PartialUser},
+};
+use crate::{Db, Result};
+
+/// 在某个对象发生变更后，向实时客户端广播的网关事件
+///
+/// 每个变更事件仅携带写入的差异（对应的 `Partial*`）以及被清除字段的列表，
+/// 使订阅者无需重新拉取整个对象即可应用更新。线格式形如
+/// `{ "type": "ServerUpdate", "id": "...", "data": { ... }, "clear": ["Icon"] }`。
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum Event {
+    /// 服务器被更新
+    ServerUpdate {
+        id: String,
+        data: PartialServer,
+        clear: Vec<FieldsServer>,
+    },
+    /// 服务器被删除
+    ServerDelete { id: String },
+    /// 用户被更新
+    UserUpdate {
+        id: String,
+        data: PartialUser,
+        clear: Vec<FieldsUser>,
+    },
+    /// 服务器角色被更新
+    RoleUpdate {
+        id: String,
+        data: PartialRole,
+        clear: Vec<FieldsRole>,
+    },
+    /// 服务器角色被删除
+    RoleDelete { id: String },
+    /// 服务器成员被更新
+    MemberUpdate {
+        id: String,
+        data: PartialMember,
+        clear: Vec<FieldsMember>,
+    },
+}
+
+/// 进程内事件总线，订阅者从这里接收每一次写入的差异
+static BUS: Lazy<broadcast::Sender<Event>> = Lazy::new(|| broadcast::channel(1024).0);
+
+/// 订阅事件总线，获得后续发布事件的接收端
+pub fn subscribe() -> broadcast::Receiver<Event> {
+    BUS.subscribe()
+}
+
+/// 事件总线：路由处理器在写入成功后通过数据库句柄调用它，把差异分发给订阅者
+#[async_trait]
+pub trait Publish {
+    /// 发布单个事件
+    async fn publish(&self, event: Event) -> Result<()>;
+}
+
+#[async_trait]
+impl Publish for Db {
+    async fn publish(&self, event: Event) -> Result<()> {
+        // 当前没有订阅者时 `send` 会返回错误，这属于正常情况，直接忽略
+        let _ = BUS.send(event);
+        Ok(())
+    }
+}
@@ -2,8 +2,8 @@
 use std::collections::HashMap;
 
 // 引入第三方库
-use num_enum::TryFromPrimitive; // 用于将整数尝试转换为枚举
-use serde::{Deserialize, Serialize}; // 用于序列化和反序列化
+use bitflags::bitflags; // 用于构建类型安全的位标志集
+use serde::{Deserialize, Deserializer, Serialize, Serializer}; // 用于序列化和反序列化
 use validator::Validate; // 用于验证数据
 
 // 引入项目内的模块或定义
@@ -68,12 +68,46 @@ pub struct SystemMessageChannels {
     pub user_banned: Option<String>,
 }
 
-/// 服务器标志枚举
-#[derive(Debug, PartialEq, Eq, TryFromPrimitive, Copy, Clone)]
-#[repr(i32)]
-pub enum ServerFlags {
-    Verified = 1, // 已验证
-    Official = 2, // 官方
+bitflags! {
+    /// 服务器标志位集
+    #[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+    pub struct ServerFlags: i32 {
+        /// 已验证
+        const VERIFIED = 1;
+        /// 官方
+        const OFFICIAL = 2;
+    }
+}
+
+// 在数据库中标志以可空整数存储；提供与类型化位集之间的转换。
+impl From<i32> for ServerFlags {
+    fn from(bits: i32) -> Self {
+        // 保留未知/未来的位，避免在往返过程中被静默丢弃
+        ServerFlags::from_bits_retain(bits)
+    }
+}
+
+// 线格式保持为普通整数以向后兼容。
+impl Serialize for ServerFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for ServerFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(ServerFlags::from_bits_retain(i32::deserialize(deserializer)?))
+    }
+}
+
+impl schemars::JsonSchema for ServerFlags {
+    fn schema_name() -> String {
+        "ServerFlags".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        i32::json_schema(gen)
+    }
 }
 
 /// 代表Revolt上的服务器
@@ -120,9 +154,9 @@ pub struct Server {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub banner: Option<File>,
 
-    // 服务器标志的位字段
+    // 服务器标志的位集
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flags: Option<i32>,
+    pub flags: Option<ServerFlags>,
 
     // 标记服务器是否不适合工作环境
     #[serde(skip_serializing_if = "if_false", default)]
@@ -135,6 +169,33 @@ pub struct Server {
     pub discoverable: bool,
 }
 
+impl Server {
+    /// 计算某成员在该服务器上的最高排名（数值越小，权限越高）
+    ///
+    /// 服务器拥有者返回 [`i64::MIN`]，凌驾于一切角色之上；没有任何角色的成员
+    /// 返回 [`i64::MAX`]，即权限最低。
+    pub fn member_rank(&self, member: &str, member_roles: &[String]) -> i64 {
+        if member == self.owner {
+            return i64::MIN;
+        }
+
+        member_roles
+            .iter()
+            .filter_map(|id| self.roles.get(id))
+            .map(|role| role.rank)
+            .min()
+            .unwrap_or(i64::MAX)
+    }
+
+    /// 判断具有给定排名的操作者是否有权管理某个排名的角色
+    ///
+    /// 仅当操作者的排名在数值上严格小于目标排名（即权限严格更高）时才被允许；排名相等或更低的
+    /// 角色在权限上等同或凌驾于操作者，因而不得被其变更。
+    pub fn has_authority_over(&self, actor_rank: i64, target_rank: i64) -> bool {
+        actor_rank < target_rank
+    }
+}
+
 /// 服务器对象上的可选字段
 #[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
 pub enum FieldsServer {
@@ -1,7 +1,7 @@
-// 尝试从原始值转换的数字枚举
-use num_enum::TryFromPrimitive;
+// 类型安全的位标志集
+use bitflags::bitflags;
 // 序列化和反序列化支持
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 // 验证支持
 use validator::Validate;
 
@@ -71,48 +71,108 @@ pub struct UserProfile {
     pub background: Option<File>,
 }
 
-/// 用户徽章位
-#[derive(Debug, PartialEq, Eq, TryFromPrimitive, Copy, Clone)]
-#[repr(i32)]
-pub enum Badges {
-    /// Revolt 开发者
-    Developer = 1,
-    /// 帮助翻译 Revolt
-    Translator = 2,
-    /// 财务支持 Revolt
-    Supporter = 4,
-    /// 梦乡皇帝
-    Adelaide = 6,
-    /// 负责任地披露了一个安全问题
-    ResponsibleDisclosure = 8,
-    /// Revolt 创始人
-    Founder = 16,
-    /// 平台管理员
-    PlatformModeration = 32,
-    /// 活跃的财务支持者
-    ActiveSupporter = 64,
-    /// 🦊🦝
-    Paw = 128,
-    /// 作为2021年前1000名用户之一加入
-    EarlyAdopter = 256,
-    /// Amogus
-    ReservedRelevantJokeBadge1 = 512,
-    /// 低分辨率的恶搞脸
-    ReservedRelevantJokeBadge2 = 1024,
-}
-
-/// 用户标志枚举
-#[derive(Debug, PartialEq, Eq, TryFromPrimitive, Copy, Clone)]
-#[repr(i32)]
-pub enum Flags {
-    /// 用户已从平台中暂停
-    Suspended = 1,
-    /// 用户已删除他们的账户
-    Deleted = 2,
-    /// 用户已被平台禁止
-    Banned = 4,
-    /// 用户被标记为垃圾邮件并从平台中移除
-    Spam = 8,
+bitflags! {
+    /// 用户徽章位集
+    #[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+    pub struct Badges: i32 {
+        /// Revolt 开发者
+        const DEVELOPER = 1;
+        /// 帮助翻译 Revolt
+        const TRANSLATOR = 2;
+        /// 财务支持 Revolt
+        const SUPPORTER = 4;
+        /// 负责任地披露了一个安全问题
+        const RESPONSIBLE_DISCLOSURE = 8;
+        /// Revolt 创始人
+        const FOUNDER = 16;
+        /// 平台管理员
+        const PLATFORM_MODERATION = 32;
+        /// 活跃的财务支持者
+        const ACTIVE_SUPPORTER = 64;
+        /// 🦊🦝
+        const PAW = 128;
+        /// 作为2021年前1000名用户之一加入
+        const EARLY_ADOPTER = 256;
+        /// Amogus
+        const RESERVED_RELEVANT_JOKE_BADGE_1 = 512;
+        /// 低分辨率的恶搞脸
+        const RESERVED_RELEVANT_JOKE_BADGE_2 = 1024;
+        /// 梦乡皇帝
+        const ADELAIDE = 2048;
+    }
+}
+
+bitflags! {
+    /// 用户标志位集
+    #[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+    pub struct Flags: i32 {
+        /// 用户已从平台中暂停
+        const SUSPENDED = 1;
+        /// 用户已删除他们的账户
+        const DELETED = 2;
+        /// 用户已被平台禁止
+        const BANNED = 4;
+        /// 用户被标记为垃圾邮件并从平台中移除
+        const SPAM = 8;
+    }
+}
+
+// 数据库以可空整数存储徽章；在整数与类型化位集之间转换。
+impl From<i32> for Badges {
+    fn from(bits: i32) -> Self {
+        Badges::from_bits_retain(bits)
+    }
+}
+
+impl Serialize for Badges {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for Badges {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Badges::from_bits_retain(i32::deserialize(deserializer)?))
+    }
+}
+
+impl schemars::JsonSchema for Badges {
+    fn schema_name() -> String {
+        "Badges".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        i32::json_schema(gen)
+    }
+}
+
+// 标志同样以可空整数持久化，保留未知位以便向前兼容。
+impl From<i32> for Flags {
+    fn from(bits: i32) -> Self {
+        Flags::from_bits_retain(bits)
+    }
+}
+
+impl Serialize for Flags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for Flags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Flags::from_bits_retain(i32::deserialize(deserializer)?))
+    }
+}
+
+impl schemars::JsonSchema for Flags {
+    fn schema_name() -> String {
+        "Flags".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        i32::json_schema(gen)
+    }
 }
 
 /// 如果用户是机器人的机器人信息
@@ -146,9 +206,9 @@ pub struct User {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub relations: Option<Vec<Relationship>>,
 
-    /// 用户徽章的位域
+    /// 用户徽章的位集
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub badges: Option<i32>,
+    pub badges: Option<Badges>,
     /// 用户当前状态
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<UserStatus>,
@@ -156,9 +216,9 @@ pub struct User {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub profile: Option<UserProfile>,
 
-    /// 用户标志枚举
+    /// 用户标志位集
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flags: Option<i32>,
+    pub flags: Option<Flags>,
     /// 此用户是否享有特权
     #[serde(skip_serializing_if = "if_false", default)]
     pub privileged: bool,
@@ -186,6 +246,97 @@ pub enum FieldsUser {
     DisplayName,
 }
 
+impl User {
+    /// 规范化一个期望的句柄：转为小写并去除不允许的字符
+    ///
+    /// 仅保留小写字母、数字与下划线，使其适合作为全局唯一的用户名。
+    pub fn normalize_username(base: &str) -> String {
+        base.trim()
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+            .collect()
+    }
+
+    /// 为一个期望的基础句柄生成候选用户名
+    ///
+    /// 在规范化后的基础名之上追加短数字后缀以及若干可发音的分隔符，
+    /// 结果已去重并且绝不包含基础名本身，供调用方逐个查询数据库后
+    /// 取前 N 个仍然空闲的候选。
+    pub fn suggest_usernames(base: &str) -> Vec<String> {
+        let base = User::normalize_username(base);
+        let mut seen = std::collections::HashSet::new();
+        let mut candidates = Vec::new();
+
+        // 基础名本身已被占用，因此从分隔符与数字后缀组合开始
+        // 分隔符必须落在允许的字符集内，否则规范化会把候选改写成另一个句柄
+        for separator in ["", "_"] {
+            for suffix in 1..=16 {
+                let candidate = format!("{base}{separator}{suffix}");
+                if candidate != base && seen.insert(candidate.clone()) {
+                    candidates.push(candidate);
+                }
+            }
+        }
+
+        candidates
+    }
+
+    /// 返回该用户当前处于好友状态的所有用户 Id
+    pub fn friend_ids(&self) -> Vec<String> {
+        self.relations
+            .as_ref()
+            .map(|relations| {
+                relations
+                    .iter()
+                    .filter(|relation| relation.status == RelationshipStatus::Friend)
+                    .map(|relation| relation.id.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 返回该用户与另一个用户之间记录的关系状态
+    pub fn relationship_with(&self, user_id: &str) -> RelationshipStatus {
+        if self.id == user_id {
+            return RelationshipStatus::User;
+        }
+
+        self.relations
+            .as_ref()
+            .and_then(|relations| relations.iter().find(|relation| relation.id == user_id))
+            .map(|relation| relation.status.clone())
+            .unwrap_or(RelationshipStatus::None)
+    }
+
+    /// 计算与另一个用户共同的好友 Id（双方都视对方好友圈中的成员为好友）
+    pub fn mutual_friend_ids(&self, other: &User) -> Vec<String> {
+        let theirs: std::collections::HashSet<String> = other.friend_ids().into_iter().collect();
+        self.friend_ids()
+            .into_iter()
+            .filter(|id| theirs.contains(id))
+            .collect()
+    }
+
+    /// 当前账户是否有资格迁移到全局唯一的用户名
+    ///
+    /// 机器人不参与迁移，已被暂停/封禁/删除的账户亦然；已经迁移过
+    /// （判别符已退役）的账户无需再次迁移。
+    pub fn can_migrate_username(&self) -> bool {
+        if self.bot.is_some() {
+            return false;
+        }
+
+        if let Some(flags) = self.flags {
+            if flags.intersects(Flags::SUSPENDED | Flags::BANNED | Flags::DELETED | Flags::SPAM) {
+                return false;
+            }
+        }
+
+        self.discriminator != "0"
+    }
+}
+
 /// 提供关于我们正在处理的用户类型提示的枚举
 pub enum UserHint {
     /// 可能是用户或机器人